@@ -30,6 +30,38 @@ impl<T> SomethingOrNothing<T> {
             Something(t) => Some(t)
         }
     }
+
+    // Lets callers write `.unwrap_or(default)` instead of matching by hand, same as `Option`.
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Nothing => default,
+            Something(t) => t
+        }
+    }
+}
+
+// These let `SomethingOrNothing` drop into the same conversions and iterator pipelines as
+// `Option`, instead of being a dead-end custom enum.
+impl<T> From<Option<T>> for SomethingOrNothing<T> {
+    fn from(o: Option<T>) -> Self {
+        SomethingOrNothing::new(o)
+    }
+}
+
+impl<T> From<SomethingOrNothing<T>> for Option<T> {
+    fn from(s: SomethingOrNothing<T>) -> Self {
+        s.to_option()
+    }
+}
+
+// Mirrors `Option`'s `IntoIterator`: yields zero or one element.
+impl<T> IntoIterator for SomethingOrNothing<T> {
+    type Item = T;
+    type IntoIter = std::option::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_option().into_iter()
+    }
 }
 
 // You can call static functions, and in particular constructors, as demonstrated below
@@ -43,21 +75,30 @@ pub trait Minimum: Copy {
     fn min(self, b: Self) -> Self;
 }
 
-// We write vec_min as a generic function over a type T that we demand to satisfy the Minimum
-// trait. This requirement is called `trait bound`. Rust automatically figures out that e is of
-// type T, which implements the Minimum trait and hence we can call the function
-pub fn vec_min<T: Minimum>(v: Vec<T>) -> SomethingOrNothing<T> {
-    let mut min = Nothing;
-    for e in v {
-        min = Something(match min {
+// `vec_reduce` is the generic fold underlying both `vec_min` and `vec_max`: it seeds `Nothing`
+// and combines every further element with `f`, so neither aggregation has to repeat the
+// match-on-`Nothing` boilerplate.
+//
+// Taking `I: IntoIterator<Item = T>` instead of `Vec<T>` means every existing call site still
+// works (`Vec` implements `IntoIterator`), while callers who already have a slice, an array, or a
+// lazy iterator no longer have to collect into a `Vec` first.
+fn vec_reduce<T: Copy, I: IntoIterator<Item = T>, F: Fn(T, T) -> T>(iter: I, f: F) -> SomethingOrNothing<T> {
+    let mut result = Nothing;
+    for e in iter.into_iter() {
+        result = Something(match result {
             Nothing => e,
-            Something(n) => {
-                e.min(n)
-            }
+            Something(n) => f(n, e)
         });
     }
 
-    min
+    result
+}
+
+// We write vec_min as a generic function over a type T that we demand to satisfy the Minimum
+// trait. This requirement is called `trait bound`. Rust automatically figures out that `a` and `b`
+// in the closure are of type T, which implements the Minimum trait and hence we can call `min`
+pub fn vec_min<T: Minimum, I: IntoIterator<Item = T>>(iter: I) -> SomethingOrNothing<T> {
+    vec_reduce(iter, |a, b| a.min(b))
 }
 
 // TO make `vec_min` usable with `Vec<i32>`, we implement the Minimum trait for i32.
@@ -67,6 +108,52 @@ impl Minimum for i32 {
     }
 }
 
+// `f32`/`f64` are only `PartialOrd`, not `Ord`, because of `NaN`. We treat `NaN` as "larger than
+// everything" so a `NaN` reading never displaces a real minimum: `x.min(NaN) == x` and
+// `NaN.min(x) == x`, while `NaN.min(NaN) == NaN`.
+impl Minimum for f32 {
+    fn min(self, b: Self) -> Self {
+        if self.is_nan() { b } else if b.is_nan() || self < b { self } else { b }
+    }
+}
+
+impl Minimum for f64 {
+    fn min(self, b: Self) -> Self {
+        if self.is_nan() { b } else if b.is_nan() || self < b { self } else { b }
+    }
+}
+
+// `Maximum` mirrors `Minimum` so part02 is not limited to a single aggregation.
+pub trait Maximum: Copy {
+    fn max(self, b: Self) -> Self;
+}
+
+impl Maximum for i32 {
+    fn max(self, b: Self) -> Self {
+        if self > b { self } else { b }
+    }
+}
+
+// Floats use the same NaN-skipping rule as `Minimum`: NaN is "larger than everything" for the
+// purposes of `min`, but that would make it dominate every `max`, so here we skip it instead and
+// let the other, real, reading win.
+impl Maximum for f32 {
+    fn max(self, b: Self) -> Self {
+        if self.is_nan() { b } else if b.is_nan() || self > b { self } else { b }
+    }
+}
+
+impl Maximum for f64 {
+    fn max(self, b: Self) -> Self {
+        if self.is_nan() { b } else if b.is_nan() || self > b { self } else { b }
+    }
+}
+
+// `vec_max` is the `vec_reduce`-powered counterpart to `vec_min`.
+pub fn vec_max<T: Maximum>(v: Vec<T>) -> SomethingOrNothing<T> {
+    vec_reduce(v, |a, b| a.max(b))
+}
+
 // We again provide the print function. This also shows that we can have multiple `impl` blocks for
 // the same type 
 // `NumberOrNothing` is just a type alias for `SomethingOrNothing` and we can provide some methods
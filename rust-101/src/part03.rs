@@ -3,27 +3,32 @@ use std::io;
 
 use crate::part02::vec_min;
 
-fn read_vec() -> Vec<i32> {
-    let mut vec: Vec<i32> = Vec::<i32>::new();
+// Generic over `T: FromStr` so it can feed the now-generic `vec_min` with `f64` or any other
+// `FromStr + Minimum` type, not just `i32`. Each line may hold multiple numbers separated by
+// whitespace; invalid tokens are skipped (and counted) rather than aborting the whole read.
+fn read_vec<T: std::str::FromStr>() -> Vec<T> {
+    let mut vec: Vec<T> = Vec::new();
+    let mut errors = 0;
 
     let stdin = io::stdin();
-    println!("Enter a list of numbers; one per line"); 
+    println!("Enter a list of numbers; multiple per line are fine");
 
     for line in stdin.lock().lines() {
         let line = line.unwrap();
-        match line.trim().parse::<i32>() {
-            Ok(num) => {
-                vec.push(num)
-            },
-            Err(_) => {
-                println!("What did I say about numbers?")
-            },
+        for token in line.split_whitespace() {
+            match token.parse::<T>() {
+                Ok(num) => vec.push(num),
+                Err(_) => errors += 1,
+            }
         }
     }
+
+    if errors > 0 {
+        println!("Skipped {} value(s) that didn't parse", errors);
+    }
     vec
 }
 
-
 pub fn main() {
     let vec = read_vec();
     let min = vec_min(vec);